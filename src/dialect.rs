@@ -0,0 +1,102 @@
+//! How a specific database wants its SQL rendered: identifier quoting and
+//! bind-parameter placeholder style.
+//!
+//! [`ToSql::to_sql`](crate::ToSql::to_sql) picks [`Generic`] by default;
+//! call [`ToSql::to_sql_dialect`](crate::ToSql::to_sql_dialect) with one
+//! of [`Postgres`], [`MySql`], or [`Sqlite`] to target a real database.
+
+/// A database's rendering rules.
+pub trait Dialect {
+    /// Wraps a single identifier (a table or column name) in this
+    /// dialect's quote characters.
+    fn quote(identifier: &str) -> String;
+
+    /// Renders the `index`th (1-indexed) bind-parameter placeholder.
+    fn placeholder(index: usize) -> String;
+}
+
+/// The dialect [`ToSql::to_sql`](crate::ToSql::to_sql) renders with when
+/// no specific database has been chosen: unquoted identifiers, `?`
+/// placeholders.
+pub struct Generic;
+
+impl Dialect for Generic {
+    fn quote(identifier: &str) -> String {
+        identifier.to_string()
+    }
+
+    fn placeholder(_index: usize) -> String {
+        "?".to_string()
+    }
+}
+
+/// # Examples
+/// ```
+/// use typed_sql::dialect::{Dialect, Postgres};
+///
+/// assert_eq!(Postgres::quote("posts"), "\"posts\"");
+/// assert_eq!(Postgres::placeholder(1), "$1");
+/// ```
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    fn quote(identifier: &str) -> String {
+        format!("\"{identifier}\"")
+    }
+
+    fn placeholder(index: usize) -> String {
+        format!("${index}")
+    }
+}
+
+/// # Examples
+/// ```
+/// use typed_sql::dialect::{Dialect, MySql};
+///
+/// assert_eq!(MySql::quote("posts"), "`posts`");
+/// assert_eq!(MySql::placeholder(1), "?");
+/// ```
+pub struct MySql;
+
+impl Dialect for MySql {
+    fn quote(identifier: &str) -> String {
+        format!("`{identifier}`")
+    }
+
+    fn placeholder(_index: usize) -> String {
+        "?".to_string()
+    }
+}
+
+/// Dialects with case-insensitive `ILIKE` matching.
+///
+/// Only Postgres has it — MySQL and Sqlite only have case-sensitive
+/// `LIKE` — so [`Field::ilike`](crate::Field::ilike) is only renderable
+/// through a path bounded by this trait; see
+/// [`Filter::to_sql_dialect`](crate::query::filter::Filter::to_sql_dialect).
+pub trait SupportsIlike: Dialect {}
+
+impl SupportsIlike for Postgres {}
+
+/// Dialects with a row-locking clause (`FOR UPDATE`/`FOR SHARE`).
+///
+/// Sqlite has no such clause, so it deliberately doesn't implement this —
+/// attaching [`Locking`](crate::query::select::Locking) to a
+/// Sqlite-targeted render is a compile error rather than silently invalid
+/// SQL.
+pub trait SupportsLocking: Dialect {}
+
+impl SupportsLocking for Postgres {}
+impl SupportsLocking for MySql {}
+
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn quote(identifier: &str) -> String {
+        format!("\"{identifier}\"")
+    }
+
+    fn placeholder(_index: usize) -> String {
+        "?".to_string()
+    }
+}
@@ -0,0 +1,160 @@
+//! The per-column handles that `#[derive(Table)]` generates on a table's
+//! `Fields` struct.
+
+use crate::query::predicate::{Eq, Gt, Ilike, Like, Lt, Neq, NotLike, Wildcard};
+use crate::query::select::{GroupOrder, Order};
+use crate::{Dialect, ToSql};
+
+/// A single column, tagged with its Rust type `T` so that comparisons
+/// against it are type-checked.
+///
+/// `#[derive(Table)]` emits one `Field<T>` per struct field, each carrying
+/// its own table and column name, so rendering one never needs outside
+/// context.
+#[derive(Debug, Clone, Copy)]
+pub struct Field<T> {
+    table: &'static str,
+    column: &'static str,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> Field<T> {
+    /// Constructs a field handle. Called from derive-macro output; not
+    /// meant to be written by hand.
+    pub const fn new(table: &'static str, column: &'static str) -> Self {
+        Self {
+            table,
+            column,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> ToSql for Field<T> {
+    fn to_sql_dialect<D: Dialect>(&self, _dialect: &D) -> String {
+        format!("{}.{}", D::quote(self.table), D::quote(self.column))
+    }
+}
+
+impl<T> GroupOrder for Field<T> {}
+impl<T> Order for Field<T> {}
+
+/// A scalar value that can appear as a literal in rendered SQL.
+///
+/// # Examples
+/// ```
+/// use typed_sql::field::Literal;
+/// use typed_sql::ToSql;
+///
+/// assert_eq!(Literal::Text("it's".into()).to_sql(), "'it''s'");
+/// assert_eq!(Literal::Integer(5).to_sql(), "5");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Text(String),
+    Integer(i64),
+    Boolean(bool),
+    Null,
+}
+
+impl ToSql for Literal {
+    // Literals render the same regardless of dialect; only identifiers
+    // and placeholders are dialect-specific.
+    fn to_sql_dialect<D: Dialect>(&self, _dialect: &D) -> String {
+        match self {
+            Literal::Text(s) => format!("'{}'", s.replace('\'', "''")),
+            Literal::Integer(i) => i.to_string(),
+            Literal::Boolean(b) => b.to_string().to_uppercase(),
+            Literal::Null => "NULL".to_string(),
+        }
+    }
+
+    // This is the one override that actually does something: every other
+    // `ToSql` impl that can carry a literal just recurses through
+    // `to_sql_params_dialect` until it bottoms out here, where the value is
+    // bound instead of inlined. Literal-free fragments (`Field`, `WildCard`,
+    // the aggregates) keep the default, which calls `to_sql_dialect`.
+    fn to_sql_params_dialect<D: Dialect>(&self, _dialect: &D, values: &mut Vec<Literal>) -> String {
+        values.push(self.clone());
+        D::placeholder(values.len())
+    }
+}
+
+/// Converts a Rust value into the [`Literal`] it renders as.
+pub trait ToLiteral {
+    fn to_literal(&self) -> Literal;
+}
+
+impl ToLiteral for String {
+    fn to_literal(&self) -> Literal {
+        Literal::Text(self.clone())
+    }
+}
+
+impl ToLiteral for i64 {
+    fn to_literal(&self) -> Literal {
+        Literal::Integer(*self)
+    }
+}
+
+impl ToLiteral for bool {
+    fn to_literal(&self) -> Literal {
+        Literal::Boolean(*self)
+    }
+}
+
+impl<T: ToLiteral> ToLiteral for Option<T> {
+    fn to_literal(&self) -> Literal {
+        match self {
+            Some(value) => value.to_literal(),
+            None => Literal::Null,
+        }
+    }
+}
+
+impl<T: ToLiteral> Field<T> {
+    /// `field = value`
+    pub fn eq<V: Into<T>>(self, value: V) -> Eq<Self, T> {
+        Eq::new(self, value.into())
+    }
+
+    /// `field != value`
+    pub fn neq<V: Into<T>>(self, value: V) -> Neq<Self, T> {
+        Neq::new(self, value.into())
+    }
+
+    /// `field < value`
+    pub fn lt<V: Into<T>>(self, value: V) -> Lt<Self, T> {
+        Lt::new(self, value.into())
+    }
+
+    /// `field > value`
+    pub fn gt<V: Into<T>>(self, value: V) -> Gt<Self, T> {
+        Gt::new(self, value.into())
+    }
+}
+
+impl Field<String> {
+    /// `field LIKE pattern`
+    pub fn like(self, term: &str, wildcard: Wildcard) -> Like<Self> {
+        Like::new(self, term, wildcard)
+    }
+
+    /// `field NOT LIKE pattern`
+    pub fn not_like(self, term: &str, wildcard: Wildcard) -> NotLike<Self> {
+        NotLike::new(self, term, wildcard)
+    }
+
+    /// `field ILIKE pattern`
+    ///
+    /// Postgres-only; other dialects don't support case-insensitive
+    /// matching this way. Building the predicate is unrestricted, but
+    /// rendering it is gated behind
+    /// [`SupportsIlike`](crate::dialect::SupportsIlike) — see
+    /// [`Filter::to_sql_dialect`](crate::query::filter::Filter::to_sql_dialect) —
+    /// so a `filter` built from this never compiles for a dialect
+    /// without `ILIKE`.
+    pub fn ilike(self, term: &str, wildcard: Wildcard) -> Ilike<Self> {
+        Ilike::new(self, term, wildcard)
+    }
+}
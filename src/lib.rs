@@ -0,0 +1,89 @@
+//! `typed-sql` builds SQL statements from ordinary Rust method chains and
+//! checks the shape of the query at compile time: you can't `GROUP BY` a
+//! column that doesn't exist, `ORDER BY` before `SELECT`-ing, or forget a
+//! `WHERE` clause's predicate type.
+//!
+//! The building blocks live in [`query`]; [`Table`] is the trait a
+//! `#[derive(Table)]` struct implements to become queryable.
+
+pub mod dialect;
+pub mod field;
+pub mod query;
+pub mod table;
+
+pub use dialect::Dialect;
+pub use field::Field;
+pub use query::Query;
+pub use table::{Table, TableQueryable};
+
+use dialect::Generic;
+use field::Literal;
+
+/// Renders a finished query as the SQL string that would be sent to the
+/// database.
+///
+/// Combinators in [`query`] build up a tree of statement fragments; the
+/// blanket impl that gives statements their trailing `;` only fires for
+/// the fragments that are valid, complete statements (a bare `Filter`
+/// with no `Select`/`Update`/`Delete` underneath it doesn't implement
+/// `ToSql`, because it isn't a statement on its own).
+///
+/// `to_sql` renders with [`Generic`](dialect::Generic) — bare identifiers,
+/// `?` placeholders; use `to_sql_dialect` to target a specific database.
+pub trait ToSql {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String;
+
+    fn to_sql(&self) -> String {
+        self.to_sql_dialect(&Generic)
+    }
+
+    /// Like [`to_sql_dialect`](ToSql::to_sql_dialect), but instead of
+    /// interpolating literal values straight into the string, renders a
+    /// bind-parameter placeholder for each one and appends the value it
+    /// stands for to `values`, in the order the placeholders appear.
+    ///
+    /// The default delegates to `to_sql_dialect` unchanged, which is
+    /// correct for fragments with no literals of their own (an identifier,
+    /// say, or a combinator that only wraps other fragments' SQL without
+    /// introducing any). [`Literal`] is where an actual value gets bound
+    /// instead of inlined; everything built on top of it (predicates,
+    /// `UPDATE ... SET`, `INSERT ... VALUES`) overrides this to thread the
+    /// accumulator down to the literals it carries.
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        let _ = values;
+        self.to_sql_dialect(dialect)
+    }
+
+    /// Renders with placeholders instead of inline literals, using
+    /// [`Generic`](dialect::Generic)'s `?` placeholder style.
+    ///
+    /// This is what removes the SQL-injection footgun of `to_sql`: the
+    /// returned values are meant to be passed to the database driver as
+    /// bind parameters, never interpolated back into the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::field::Literal;
+    /// use typed_sql::{Query, Table, ToSql};
+    ///
+    /// #[derive(Table)]
+    /// struct Post {
+    ///     content: String,
+    /// }
+    ///
+    /// let stmt = Post::table().select().filter(|p| p.content.eq("foo"));
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql_params(),
+    ///     (
+    ///         "SELECT * FROM posts WHERE posts.content = ?;".to_string(),
+    ///         vec![Literal::Text("foo".to_string())]
+    ///     )
+    /// );
+    /// ```
+    fn to_sql_params(&self) -> (String, Vec<Literal>) {
+        let mut values = Vec::new();
+        let sql = self.to_sql_params_dialect(&Generic, &mut values);
+        (sql, values)
+    }
+}
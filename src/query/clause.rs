@@ -0,0 +1,29 @@
+//! Internal plumbing shared by every statement combinator.
+
+use crate::field::Literal;
+use crate::{Dialect, ToSql};
+
+/// A statement fragment that knows how to render itself *without* the
+/// trailing `;` — combinators wrap an inner `Clause` and append their own
+/// piece of SQL to it. [`ToSql`] is derived from this blanket impl so the
+/// terminator is only ever written once, by whichever combinator ends up
+/// outermost.
+pub(crate) trait Clause {
+    fn render<D: Dialect>(&self, dialect: &D) -> String;
+
+    /// The `render` counterpart of
+    /// [`to_sql_params_dialect`](crate::ToSql::to_sql_params_dialect):
+    /// renders without the trailing `;`, binding literals into `values`
+    /// instead of inlining them.
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String;
+}
+
+impl<T: Clause> ToSql for T {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("{};", self.render(dialect))
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!("{};", self.render_params(dialect, values))
+    }
+}
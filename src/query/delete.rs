@@ -0,0 +1,36 @@
+//! `DELETE FROM`.
+
+use std::marker::PhantomData;
+
+use super::clause::Clause;
+use super::filter::Filterable;
+use crate::field::Literal;
+use crate::table::Table;
+use crate::Dialect;
+
+/// `DELETE FROM <table>`
+pub struct Delete<T> {
+    _table: PhantomData<T>,
+}
+
+impl<T> Delete<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            _table: PhantomData,
+        }
+    }
+}
+
+impl<T: Table> Filterable for Delete<T> {
+    type Fields = T::Fields;
+}
+
+impl<T: Table> Clause for Delete<T> {
+    fn render<D: Dialect>(&self, _dialect: &D) -> String {
+        format!("DELETE FROM {}", D::quote(T::NAME))
+    }
+
+    fn render_params<D: Dialect>(&self, _dialect: &D, _values: &mut Vec<Literal>) -> String {
+        format!("DELETE FROM {}", D::quote(T::NAME))
+    }
+}
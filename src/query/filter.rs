@@ -0,0 +1,115 @@
+//! The `WHERE` clause.
+
+use super::clause::Clause;
+use super::predicate::{Ilike, Predicate};
+use crate::dialect::SupportsIlike;
+use crate::field::Literal;
+use crate::{Dialect, ToSql};
+
+/// Implemented by anything a `WHERE` clause can be attached to (a table, a
+/// `SELECT`, an `UPDATE`, a `DELETE`); supplies the `Fields` struct that
+/// `filter`'s closure receives.
+pub trait Filterable {
+    type Fields: Default;
+}
+
+/// `<source> WHERE <predicate>`
+pub struct Filter<S, P> {
+    pub(crate) source: S,
+    pub(crate) predicate: P,
+}
+
+impl<S, P> Filter<S, P> {
+    pub(crate) fn new(source: S, predicate: P) -> Self {
+        Self { source, predicate }
+    }
+}
+
+impl<S: Clause, P: Predicate> Clause for Filter<S, P> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} WHERE {}",
+            self.source.render(dialect),
+            self.predicate.to_sql_dialect(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} WHERE {}",
+            self.source.render_params(dialect, values),
+            self.predicate.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
+
+// `Clause` is deliberately `pub(crate)` (sealed — see `query::clause`), so
+// this inherent impl intentionally bounds public methods on a private
+// trait; that's fine, callers just can't implement `Clause` themselves.
+#[allow(private_bounds)]
+impl<S: Clause, F: ToSql> Filter<S, Ilike<F>> {
+    /// Renders `<source> WHERE <field> ILIKE <pattern>;` — only for
+    /// dialects that [`SupportsIlike`] (Postgres). `Ilike` doesn't
+    /// implement `Predicate`, so this is the only way to render a
+    /// `filter` built from [`Field::ilike`](crate::Field::ilike); it
+    /// simply doesn't compile for any other dialect.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::dialect::Postgres;
+    /// use typed_sql::query::Wildcard;
+    /// use typed_sql::{Field, Query, Table};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct Post {
+    /// //     title: String,
+    /// // }
+    /// struct Post;
+    ///
+    /// struct PostFields {
+    ///     title: Field<String>,
+    /// }
+    ///
+    /// impl Default for PostFields {
+    ///     fn default() -> Self {
+    ///         Self { title: Field::new("posts", "title") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Post {
+    ///     const NAME: &'static str = "posts";
+    ///     type Fields = PostFields;
+    /// }
+    ///
+    /// let stmt = Post::table()
+    ///     .select()
+    ///     .filter(|p| p.title.ilike("foo", Wildcard::Both));
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql_dialect(&Postgres),
+    ///     "SELECT * FROM \"posts\" WHERE \"posts\".\"title\" ILIKE '%foo%';"
+    /// );
+    /// ```
+    pub fn to_sql_dialect<D: SupportsIlike>(&self, dialect: &D) -> String {
+        format!(
+            "{} WHERE {};",
+            self.source.render(dialect),
+            self.predicate.render(dialect)
+        )
+    }
+
+    /// The `to_sql_dialect` counterpart that binds literals instead of
+    /// inlining them; see [`ToSql::to_sql_params_dialect`](crate::ToSql::to_sql_params_dialect).
+    pub fn to_sql_params_dialect<D: SupportsIlike>(
+        &self,
+        dialect: &D,
+        values: &mut Vec<Literal>,
+    ) -> String {
+        format!(
+            "{} WHERE {};",
+            self.source.render_params(dialect, values),
+            self.predicate.render_params(dialect, values)
+        )
+    }
+}
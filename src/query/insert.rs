@@ -0,0 +1,99 @@
+//! `INSERT INTO`.
+
+use std::marker::PhantomData;
+
+use super::clause::Clause;
+use crate::field::Literal;
+use crate::table::Table;
+use crate::Dialect;
+
+/// A value that can be inserted as one row.
+pub trait Insertable {
+    fn columns(&self) -> String;
+    fn values<D: Dialect>(&self, dialect: &D) -> String;
+
+    /// The `Insertable` counterpart of
+    /// [`ToSql::to_sql_params_dialect`](crate::ToSql::to_sql_params_dialect):
+    /// binds each value instead of inlining it.
+    fn values_params<D: Dialect>(&self, dialect: &D, bound: &mut Vec<Literal>) -> String;
+}
+
+/// `INSERT INTO <table> (...) VALUES (...)`
+pub struct InsertStatement<T, I> {
+    value: I,
+    _table: PhantomData<T>,
+}
+
+impl<T, I> InsertStatement<T, I> {
+    pub(crate) fn new(value: I) -> Self {
+        Self {
+            value,
+            _table: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, I: Insertable> Clause for InsertStatement<T, I> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            D::quote(T::NAME),
+            self.value.columns(),
+            self.value.values(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            D::quote(T::NAME),
+            self.value.columns(),
+            self.value.values_params(dialect, values)
+        )
+    }
+}
+
+/// Wraps a batch of rows so they're inserted with a single `INSERT INTO
+/// ... VALUES (...), (...), ...` instead of one statement per row.
+pub struct Values<I> {
+    rows: I,
+}
+
+impl<I> Values<I> {
+    pub(crate) fn new(rows: I) -> Self {
+        Self { rows }
+    }
+}
+
+impl<I> Insertable for Values<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Insertable,
+{
+    fn columns(&self) -> String {
+        self.rows
+            .clone()
+            .into_iter()
+            .next()
+            .map(|row| row.columns())
+            .unwrap_or_default()
+    }
+
+    fn values<D: Dialect>(&self, dialect: &D) -> String {
+        self.rows
+            .clone()
+            .into_iter()
+            .map(|row| row.values(dialect))
+            .collect::<Vec<_>>()
+            .join("), (")
+    }
+
+    fn values_params<D: Dialect>(&self, dialect: &D, bound: &mut Vec<Literal>) -> String {
+        self.rows
+            .clone()
+            .into_iter()
+            .map(|row| row.values_params(dialect, bound))
+            .collect::<Vec<_>>()
+            .join("), (")
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{table::TableQueryable, Table};
 
+mod clause;
+
 pub mod delete;
 use delete::Delete;
 
@@ -12,13 +14,16 @@ pub use insert::Insertable;
 use insert::{InsertStatement, Values};
 
 pub mod predicate;
-pub use predicate::Predicate;
+pub use predicate::{Predicate, Wildcard};
 use predicate::{And, Or};
 
 pub mod select;
-use select::queryable::{Count, Queryable, WildCard};
-use select::{GroupBy, GroupOrder, Limit, Order, OrderBy, SelectStatement, Selectable};
-pub use select::{Join, Joined, Select};
+use select::queryable::{Avg, Count, Max, Min, Queryable, Sum, WildCard};
+use select::{
+    Distinct, DistinctOn, GroupBy, GroupOrder, Limit, Offset, Order, OrderBy, SelectStatement,
+    Selectable,
+};
+pub use select::{Combinable, Combine, Join, Joined, Lockable, Locking, Select};
 
 pub mod update;
 use update::{Update, UpdateSet};
@@ -52,6 +57,57 @@ pub trait Query: Sized {
         SelectStatement::new(self, query)
     }
 
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Query, Table, ToSql};
+    ///
+    /// #[derive(Table)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// let stmt = User::table().distinct();
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT DISTINCT * FROM users;");
+    /// ```
+    fn distinct(self) -> Distinct<Self, WildCard>
+    where
+        Self: Selectable,
+    {
+        Distinct::new(self, WildCard)
+    }
+
+    /// `SELECT DISTINCT ON (<columns>) ...` — Postgres-only, so the
+    /// statement it returns only has a `to_sql` reachable for
+    /// [`Postgres`](crate::dialect::Postgres); nothing else in this crate
+    /// renders it.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Query, Table};
+    ///
+    /// #[derive(Table)]
+    /// struct User {
+    ///     id: i64,
+    ///     name: String,
+    /// }
+    ///
+    /// let stmt = User::table().distinct_on(|u| u.id.then(u.name));
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql(),
+    ///     "SELECT DISTINCT ON (\"users\".\"id\",\"users\".\"name\") * FROM \"users\";"
+    /// );
+    /// ```
+    fn distinct_on<F, O>(self, f: F) -> DistinctOn<Self, O, WildCard>
+    where
+        Self: Selectable,
+        F: FnOnce(Self::Fields) -> O,
+        O: GroupOrder,
+    {
+        DistinctOn::new(self, f(Default::default()), WildCard)
+    }
+
     /// # Examples
     /// ```
     /// use typed_sql::{Query, Table, ToSql};
@@ -85,6 +141,162 @@ pub trait Query: Sized {
         self.query(Count::new(f(Default::default())))
     }
 
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct Post {
+    /// //     views: i64,
+    /// // }
+    /// struct Post;
+    ///
+    /// struct PostFields {
+    ///     views: Field<i64>,
+    /// }
+    ///
+    /// impl Default for PostFields {
+    ///     fn default() -> Self {
+    ///         Self { views: Field::new("posts", "views") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Post {
+    ///     const NAME: &'static str = "posts";
+    ///     type Fields = PostFields;
+    /// }
+    ///
+    /// let stmt = Post::table().sum(|post| post.views);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT SUM(posts.views) FROM posts;");
+    /// ```
+    fn sum<F, T>(self, f: F) -> SelectStatement<Self, Sum<T>>
+    where
+        Self: Selectable,
+        F: FnOnce(Self::Fields) -> T,
+        Sum<T>: Queryable,
+    {
+        self.query(Sum::new(f(Default::default())))
+    }
+
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct Post {
+    /// //     views: i64,
+    /// // }
+    /// struct Post;
+    ///
+    /// struct PostFields {
+    ///     views: Field<i64>,
+    /// }
+    ///
+    /// impl Default for PostFields {
+    ///     fn default() -> Self {
+    ///         Self { views: Field::new("posts", "views") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Post {
+    ///     const NAME: &'static str = "posts";
+    ///     type Fields = PostFields;
+    /// }
+    ///
+    /// let stmt = Post::table().avg(|post| post.views);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT AVG(posts.views) FROM posts;");
+    /// ```
+    fn avg<F, T>(self, f: F) -> SelectStatement<Self, Avg<T>>
+    where
+        Self: Selectable,
+        F: FnOnce(Self::Fields) -> T,
+        Avg<T>: Queryable,
+    {
+        self.query(Avg::new(f(Default::default())))
+    }
+
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct Post {
+    /// //     views: i64,
+    /// // }
+    /// struct Post;
+    ///
+    /// struct PostFields {
+    ///     views: Field<i64>,
+    /// }
+    ///
+    /// impl Default for PostFields {
+    ///     fn default() -> Self {
+    ///         Self { views: Field::new("posts", "views") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Post {
+    ///     const NAME: &'static str = "posts";
+    ///     type Fields = PostFields;
+    /// }
+    ///
+    /// let stmt = Post::table().min(|post| post.views);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT MIN(posts.views) FROM posts;");
+    /// ```
+    fn min<F, T>(self, f: F) -> SelectStatement<Self, Min<T>>
+    where
+        Self: Selectable,
+        F: FnOnce(Self::Fields) -> T,
+        Min<T>: Queryable,
+    {
+        self.query(Min::new(f(Default::default())))
+    }
+
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct Post {
+    /// //     views: i64,
+    /// // }
+    /// struct Post;
+    ///
+    /// struct PostFields {
+    ///     views: Field<i64>,
+    /// }
+    ///
+    /// impl Default for PostFields {
+    ///     fn default() -> Self {
+    ///         Self { views: Field::new("posts", "views") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Post {
+    ///     const NAME: &'static str = "posts";
+    ///     type Fields = PostFields;
+    /// }
+    ///
+    /// let stmt = Post::table().max(|post| post.views);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT MAX(posts.views) FROM posts;");
+    /// ```
+    fn max<F, T>(self, f: F) -> SelectStatement<Self, Max<T>>
+    where
+        Self: Selectable,
+        F: FnOnce(Self::Fields) -> T,
+        Max<T>: Queryable,
+    {
+        self.query(Max::new(f(Default::default())))
+    }
+
     /// ```
     /// use typed_sql::{Query, Table};
     ///
@@ -307,6 +519,82 @@ pub trait Query: Sized {
     {
         Limit::new(self, limit)
     }
+
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table().select().limit(10).offset(20);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT * FROM users LIMIT 10 OFFSET 20;");
+    /// ```
+    fn offset(self, offset: usize) -> Offset<Self>
+    where
+        Self: Select,
+    {
+        Offset::new(self, offset)
+    }
+
+    /// Shorthand for `limit(per_page).offset(page * per_page)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table().select().paginate(2, 10);
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT * FROM users LIMIT 10 OFFSET 20;");
+    /// ```
+    fn paginate(self, page: usize, per_page: usize) -> Offset<Limit<Self>>
+    where
+        Self: Select,
+    {
+        self.limit(per_page).offset(page * per_page)
+    }
 }
 
 impl<T> Query for T {}
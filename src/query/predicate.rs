@@ -0,0 +1,310 @@
+//! Boolean expressions that can appear after `WHERE` or `HAVING`.
+
+use crate::field::{Literal, ToLiteral};
+use crate::{Dialect, ToSql};
+
+// `And`/`Or`/the comparison and string-match macros below all override
+// `to_sql_params_dialect` to recurse with the params-aware method instead
+// of falling back to `ToSql`'s default (which would just call
+// `to_sql_dialect` and inline any literal nested underneath).
+
+/// A rendered boolean expression, combinable with [`Query::and`](crate::Query::and)
+/// and [`Query::or`](crate::Query::or).
+pub trait Predicate: ToSql {}
+
+/// `head AND tail`
+pub struct And<H, T> {
+    pub(crate) head: H,
+    pub(crate) tail: T,
+}
+
+impl<H: ToSql, T: ToSql> ToSql for And<H, T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} AND {}",
+            self.head.to_sql_dialect(dialect),
+            self.tail.to_sql_dialect(dialect)
+        )
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} AND {}",
+            self.head.to_sql_params_dialect(dialect, values),
+            self.tail.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
+
+impl<H: Predicate, T: Predicate> Predicate for And<H, T> {}
+
+/// `head OR tail`
+pub struct Or<H, T> {
+    pub(crate) head: H,
+    pub(crate) tail: T,
+}
+
+impl<H: ToSql, T: ToSql> ToSql for Or<H, T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} OR {}",
+            self.head.to_sql_dialect(dialect),
+            self.tail.to_sql_dialect(dialect)
+        )
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} OR {}",
+            self.head.to_sql_params_dialect(dialect, values),
+            self.tail.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
+
+impl<H: Predicate, T: Predicate> Predicate for Or<H, T> {}
+
+macro_rules! comparison {
+    ($name:ident, $op:literal) => {
+        #[doc = concat!("`field ", $op, " value`")]
+        pub struct $name<F, T> {
+            field: F,
+            value: T,
+        }
+
+        impl<F, T: ToLiteral> $name<F, T> {
+            pub(crate) fn new(field: F, value: T) -> Self {
+                Self { field, value }
+            }
+        }
+
+        impl<F: ToSql, T: ToLiteral> ToSql for $name<F, T> {
+            fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+                format!(
+                    "{} {} {}",
+                    self.field.to_sql_dialect(dialect),
+                    $op,
+                    self.value.to_literal().to_sql_dialect(dialect)
+                )
+            }
+
+            fn to_sql_params_dialect<D: Dialect>(
+                &self,
+                dialect: &D,
+                values: &mut Vec<Literal>,
+            ) -> String {
+                format!(
+                    "{} {} {}",
+                    self.field.to_sql_params_dialect(dialect, values),
+                    $op,
+                    self.value.to_literal().to_sql_params_dialect(dialect, values)
+                )
+            }
+        }
+
+        impl<F: ToSql, T: ToLiteral> Predicate for $name<F, T> {}
+    };
+}
+
+comparison!(Eq, "=");
+comparison!(Neq, "!=");
+comparison!(Lt, "<");
+comparison!(Gt, ">");
+
+/// Where the `%` goes in a `LIKE`/`ILIKE` pattern.
+///
+/// # Examples
+/// ```
+/// use typed_sql::{Field, Query, Table, ToSql};
+/// use typed_sql::query::Wildcard;
+///
+/// // What `#[derive(Table)]` would generate for:
+/// // #[derive(Table)]
+/// // struct Post {
+/// //     content: String,
+/// // }
+/// struct Post;
+///
+/// struct PostFields {
+///     content: Field<String>,
+/// }
+///
+/// impl Default for PostFields {
+///     fn default() -> Self {
+///         Self { content: Field::new("posts", "content") }
+///     }
+/// }
+///
+/// impl Table for Post {
+///     const NAME: &'static str = "posts";
+///     type Fields = PostFields;
+/// }
+///
+/// let stmt = Post::table().select().filter(|p| p.content.like("foo", Wildcard::Both));
+///
+/// assert_eq!(stmt.to_sql(), "SELECT * FROM posts WHERE posts.content LIKE '%foo%';");
+/// ```
+pub enum Wildcard {
+    Before,
+    After,
+    Both,
+    None,
+}
+
+impl Wildcard {
+    fn apply(&self, term: &str) -> String {
+        match self {
+            Wildcard::Before => format!("%{term}"),
+            Wildcard::After => format!("{term}%"),
+            Wildcard::Both => format!("%{term}%"),
+            Wildcard::None => term.to_string(),
+        }
+    }
+}
+
+macro_rules! string_match {
+    ($name:ident, $op:literal) => {
+        #[doc = concat!("`field ", $op, " pattern`")]
+        pub struct $name<F> {
+            field: F,
+            pattern: Literal,
+        }
+
+        impl<F> $name<F> {
+            pub(crate) fn new(field: F, term: &str, wildcard: Wildcard) -> Self {
+                Self {
+                    field,
+                    pattern: Literal::Text(wildcard.apply(term)),
+                }
+            }
+        }
+
+        impl<F: ToSql> ToSql for $name<F> {
+            fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+                format!(
+                    "{} {} {}",
+                    self.field.to_sql_dialect(dialect),
+                    $op,
+                    self.pattern.to_sql_dialect(dialect)
+                )
+            }
+
+            fn to_sql_params_dialect<D: Dialect>(
+                &self,
+                dialect: &D,
+                values: &mut Vec<Literal>,
+            ) -> String {
+                format!(
+                    "{} {} {}",
+                    self.field.to_sql_params_dialect(dialect, values),
+                    $op,
+                    self.pattern.to_sql_params_dialect(dialect, values)
+                )
+            }
+        }
+
+        impl<F: ToSql> Predicate for $name<F> {}
+    };
+}
+
+string_match!(Like, "LIKE");
+string_match!(NotLike, "NOT LIKE");
+
+/// `field ILIKE pattern`
+///
+/// Postgres-only, so unlike [`Like`]/[`NotLike`] this deliberately does
+/// *not* implement [`Predicate`]/[`ToSql`] — those render for every
+/// [`Dialect`], and Sqlite/MySQL have no case-insensitive `ILIKE`.
+/// Building one is still generic (`Field::ilike` works everywhere a
+/// closure can return it), but the only way to render it is
+/// [`Filter::to_sql_dialect`](super::filter::Filter::to_sql_dialect) (or
+/// its `to_sql_params_dialect` counterpart), which is bounded by
+/// [`SupportsIlike`](crate::dialect::SupportsIlike) — the same kind of
+/// dialect gate [`Locking`](super::select::Locking) has with
+/// [`SupportsLocking`](crate::dialect::SupportsLocking).
+pub struct Ilike<F> {
+    field: F,
+    pattern: Literal,
+}
+
+impl<F> Ilike<F> {
+    pub(crate) fn new(field: F, term: &str, wildcard: Wildcard) -> Self {
+        Self {
+            field,
+            pattern: Literal::Text(wildcard.apply(term)),
+        }
+    }
+}
+
+impl<F: ToSql> Ilike<F> {
+    pub(crate) fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} ILIKE {}",
+            self.field.to_sql_dialect(dialect),
+            self.pattern.to_sql_dialect(dialect)
+        )
+    }
+
+    pub(crate) fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} ILIKE {}",
+            self.field.to_sql_params_dialect(dialect, values),
+            self.pattern.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
+
+// `Eq`/`And` double as the building blocks of an `UPDATE ... SET` list: the
+// same `p.id.eq(2).and(p.name.eq("foo"))` expression is a `Predicate` in a
+// `WHERE` clause and an `UpdateSet` in an `UPDATE`, just joined with `,`
+// instead of `AND`.
+mod update_set {
+    use super::{And, Eq};
+    use crate::field::{Literal, ToLiteral};
+    use crate::query::update::UpdateSet;
+    use crate::{Dialect, ToSql};
+
+    impl<F: ToSql, T: ToLiteral> UpdateSet for Eq<F, T> {
+        fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+            format!(
+                "{} = {}",
+                self.field.to_sql_dialect(dialect),
+                self.value.to_literal().to_sql_dialect(dialect)
+            )
+        }
+
+        fn to_sql_params_dialect<D: Dialect>(
+            &self,
+            dialect: &D,
+            values: &mut Vec<Literal>,
+        ) -> String {
+            format!(
+                "{} = {}",
+                self.field.to_sql_params_dialect(dialect, values),
+                self.value.to_literal().to_sql_params_dialect(dialect, values)
+            )
+        }
+    }
+
+    impl<H: UpdateSet, T: UpdateSet> UpdateSet for And<H, T> {
+        fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+            format!(
+                "{},{}",
+                self.head.to_sql_dialect(dialect),
+                self.tail.to_sql_dialect(dialect)
+            )
+        }
+
+        fn to_sql_params_dialect<D: Dialect>(
+            &self,
+            dialect: &D,
+            values: &mut Vec<Literal>,
+        ) -> String {
+            format!(
+                "{},{}",
+                self.head.to_sql_params_dialect(dialect, values),
+                self.tail.to_sql_params_dialect(dialect, values)
+            )
+        }
+    }
+}
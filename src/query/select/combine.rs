@@ -0,0 +1,74 @@
+//! `UNION`, `UNION ALL`, `INTERSECT`, and `EXCEPT` between two `SELECT`s.
+
+use super::Select;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::Dialect;
+
+/// Which set operation joins the two sides of a [`Combine`].
+pub(crate) enum Operator {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl Operator {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Operator::Union => "UNION",
+            Operator::UnionAll => "UNION ALL",
+            Operator::Intersect => "INTERSECT",
+            Operator::Except => "EXCEPT",
+        }
+    }
+}
+
+/// `<left> <UNION|UNION ALL|INTERSECT|EXCEPT> <right>`
+///
+/// Built from [`Combinable::union`](super::Combinable::union)/
+/// [`union_all`](super::Combinable::union_all)/
+/// [`intersect`](super::Combinable::intersect)/
+/// [`except`](super::Combinable::except); both sides are required to
+/// share the same `Query` projection so they're drawing the same column
+/// list.
+pub struct Combine<L, R> {
+    left: L,
+    right: R,
+    operator: Operator,
+}
+
+impl<L, R> Combine<L, R> {
+    pub(crate) fn new(left: L, right: R, operator: Operator) -> Self {
+        Self {
+            left,
+            right,
+            operator,
+        }
+    }
+}
+
+impl<L: Select, R: Select<Query = L::Query>> Select for Combine<L, R> {
+    type Selectable = L::Selectable;
+    type Query = L::Query;
+}
+
+impl<L: Clause, R: Clause> Clause for Combine<L, R> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} {} {}",
+            self.left.render(dialect),
+            self.operator.keyword(),
+            self.right.render(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} {} {}",
+            self.left.render_params(dialect, values),
+            self.operator.keyword(),
+            self.right.render_params(dialect, values)
+        )
+    }
+}
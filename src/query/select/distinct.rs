@@ -0,0 +1,209 @@
+//! `SELECT DISTINCT` and the Postgres-only `SELECT DISTINCT ON (...)`.
+
+use std::marker::PhantomData;
+
+use super::queryable::Queryable;
+use super::{GroupOrder, Order, Select, Selectable};
+use crate::dialect::Postgres;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::query::filter::Filterable;
+use crate::Dialect;
+
+/// `SELECT DISTINCT <query> FROM <table>`
+pub struct Distinct<S, Q> {
+    _source: PhantomData<S>,
+    query: Q,
+}
+
+impl<S, Q> Distinct<S, Q> {
+    pub(crate) fn new(_source: S, query: Q) -> Self {
+        Self {
+            _source: PhantomData,
+            query,
+        }
+    }
+}
+
+impl<S: Selectable, Q: Queryable> Select for Distinct<S, Q> {
+    type Selectable = S;
+    type Query = Q;
+}
+
+impl<S: Selectable, Q> Filterable for Distinct<S, Q> {
+    type Fields = S::Fields;
+}
+
+impl<S: Selectable, Q: Queryable> Clause for Distinct<S, Q> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "SELECT DISTINCT {} FROM {}",
+            self.query.to_sql_dialect(dialect),
+            D::quote(S::table_name())
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "SELECT DISTINCT {} FROM {}",
+            self.query.to_sql_params_dialect(dialect, values),
+            D::quote(S::table_name())
+        )
+    }
+}
+
+/// `SELECT DISTINCT ON (<columns>) <query> FROM <table>`
+///
+/// Postgres-only. Unlike most statements in this crate, `DistinctOn`
+/// doesn't implement `ToSql`/`Clause` — there's no portable rendering of
+/// `DISTINCT ON` for other dialects, so rather than pick one at runtime
+/// (and silently emit invalid SQL for, say, MySQL), it's only reachable
+/// through the inherent [`to_sql`](DistinctOn::to_sql) below, which is
+/// hardwired to [`Postgres`]. Calling it on a chain built for another
+/// dialect is a compile error because the method simply doesn't exist for
+/// that case; there's no way to ask for it.
+pub struct DistinctOn<S, O, Q> {
+    _source: PhantomData<S>,
+    columns: O,
+    query: Q,
+}
+
+impl<S, O, Q> DistinctOn<S, O, Q> {
+    pub(crate) fn new(_source: S, columns: O, query: Q) -> Self {
+        Self {
+            _source: PhantomData,
+            columns,
+            query,
+        }
+    }
+}
+
+impl<S: Selectable, O: GroupOrder, Q: Queryable> Select for DistinctOn<S, O, Q> {
+    type Selectable = S;
+    type Query = Q;
+}
+
+impl<S: Selectable, O: GroupOrder, Q> Filterable for DistinctOn<S, O, Q> {
+    type Fields = S::Fields;
+}
+
+impl<S: Selectable, O: GroupOrder, Q: Queryable> DistinctOn<S, O, Q> {
+    /// Renders as Postgres SQL — the only dialect `DISTINCT ON` exists in.
+    pub fn to_sql(&self) -> String {
+        format!(
+            "SELECT DISTINCT ON ({}) {} FROM {};",
+            self.columns.to_sql_dialect(&Postgres),
+            self.query.to_sql_dialect(&Postgres),
+            Postgres::quote(S::table_name())
+        )
+    }
+
+    /// The `to_sql` counterpart that binds literals instead of inlining
+    /// them; see [`ToSql::to_sql_params`](crate::ToSql::to_sql_params).
+    /// Takes no dialect argument for the same reason [`to_sql`](Self::to_sql)
+    /// doesn't — `DISTINCT ON` is always rendered as Postgres.
+    pub fn to_sql_params(&self, values: &mut Vec<Literal>) -> String {
+        format!(
+            "SELECT DISTINCT ON ({}) {} FROM {};",
+            self.columns.to_sql_params_dialect(&Postgres, values),
+            self.query.to_sql_params_dialect(&Postgres, values),
+            Postgres::quote(S::table_name())
+        )
+    }
+
+    /// `DISTINCT ON` only picks one row per distinct value of its column
+    /// list, with "one" decided by row order — so Postgres' own docs call
+    /// an `ORDER BY` matching (or extending) that column list effectively
+    /// mandatory for a deterministic result. Like
+    /// [`GroupBy::having`](super::GroupBy::having), this is an inherent
+    /// method; it returns a new Postgres-only wrapper, since `DistinctOn`
+    /// itself has no portable `Clause`/`ToSql` to build `OrderBy` on top of.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// //     name: String,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    ///     name: Field<String>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self {
+    ///             id: Field::new("users", "id"),
+    ///             name: Field::new("users", "name"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table()
+    ///     .distinct_on(|u| u.id)
+    ///     .order_by(|u| u.id.then(u.name));
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql(),
+    ///     "SELECT DISTINCT ON (\"users\".\"id\") * FROM \"users\" \
+    ///      ORDER BY \"users\".\"id\",\"users\".\"name\";"
+    /// );
+    /// ```
+    pub fn order_by<F, R>(self, f: F) -> DistinctOnOrdered<S, O, Q, R>
+    where
+        F: FnOnce(S::Fields) -> R,
+        R: Order,
+    {
+        let order = f(Default::default());
+        DistinctOnOrdered {
+            distinct: self,
+            order,
+        }
+    }
+}
+
+/// `<DistinctOn> ORDER BY <order>`
+///
+/// Built from [`DistinctOn::order_by`]; like `DistinctOn`, only reachable
+/// through its own [`to_sql`](Self::to_sql)/[`to_sql_params`](Self::to_sql_params),
+/// hardwired to [`Postgres`].
+pub struct DistinctOnOrdered<S, O, Q, R> {
+    distinct: DistinctOn<S, O, Q>,
+    order: R,
+}
+
+impl<S: Selectable, O: GroupOrder, Q: Queryable, R: Order> DistinctOnOrdered<S, O, Q, R> {
+    /// Renders as Postgres SQL — the only dialect `DISTINCT ON` exists in.
+    pub fn to_sql(&self) -> String {
+        format!(
+            "SELECT DISTINCT ON ({}) {} FROM {} ORDER BY {};",
+            self.distinct.columns.to_sql_dialect(&Postgres),
+            self.distinct.query.to_sql_dialect(&Postgres),
+            Postgres::quote(S::table_name()),
+            self.order.to_sql_dialect(&Postgres)
+        )
+    }
+
+    /// The `to_sql` counterpart that binds literals instead of inlining
+    /// them; see [`ToSql::to_sql_params`](crate::ToSql::to_sql_params).
+    pub fn to_sql_params(&self, values: &mut Vec<Literal>) -> String {
+        format!(
+            "SELECT DISTINCT ON ({}) {} FROM {} ORDER BY {};",
+            self.distinct.columns.to_sql_params_dialect(&Postgres, values),
+            self.distinct.query.to_sql_params_dialect(&Postgres, values),
+            Postgres::quote(S::table_name()),
+            self.order.to_sql_params_dialect(&Postgres, values)
+        )
+    }
+}
@@ -0,0 +1,111 @@
+//! `GROUP BY`.
+
+use super::Select;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::{Dialect, ToSql};
+
+/// One or more columns to group by.
+///
+/// Implemented directly by [`Field`](crate::Field) for a single column,
+/// and by tuples (built up via [`then`](GroupOrder::then)) for several.
+pub trait GroupOrder: ToSql {
+    /// Adds another column to group by.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// //     name: String,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    ///     name: Field<String>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self {
+    ///             id: Field::new("users", "id"),
+    ///             name: Field::new("users", "name"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table().select().group_by(|user| user.id.then(user.name));
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT * FROM users GROUP BY users.id,users.name;");
+    /// ```
+    fn then<O: GroupOrder>(self, other: O) -> (Self, O)
+    where
+        Self: Sized,
+    {
+        (self, other)
+    }
+}
+
+impl<A: ToSql, B: ToSql> ToSql for (A, B) {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{},{}",
+            self.0.to_sql_dialect(dialect),
+            self.1.to_sql_dialect(dialect)
+        )
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{},{}",
+            self.0.to_sql_params_dialect(dialect, values),
+            self.1.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
+
+impl<A: GroupOrder, B: GroupOrder> GroupOrder for (A, B) {}
+
+/// `<source> GROUP BY <group>`
+pub struct GroupBy<S, O> {
+    source: S,
+    group: O,
+}
+
+impl<S, O> GroupBy<S, O> {
+    pub(crate) fn new(source: S, group: O) -> Self {
+        Self { source, group }
+    }
+}
+
+impl<S: Select, O> Select for GroupBy<S, O> {
+    type Selectable = S::Selectable;
+    type Query = S::Query;
+}
+
+impl<S: Clause, O: GroupOrder> Clause for GroupBy<S, O> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} GROUP BY {}",
+            self.source.render(dialect),
+            self.group.to_sql_dialect(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} GROUP BY {}",
+            self.source.render_params(dialect, values),
+            self.group.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
@@ -0,0 +1,86 @@
+//! `HAVING`, filtering on the results of a `GROUP BY`.
+
+use super::{GroupBy, GroupOrder, Select, Selectable};
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::query::predicate::Predicate;
+use crate::Dialect;
+
+/// `<group by> HAVING <predicate>`
+///
+/// Only buildable from [`GroupBy::having`] — there's no `having` on a bare
+/// `SELECT`, since `HAVING` only makes sense once rows have been grouped.
+pub struct Having<G, P> {
+    source: G,
+    predicate: P,
+}
+
+impl<G, P> Having<G, P> {
+    fn new(source: G, predicate: P) -> Self {
+        Self { source, predicate }
+    }
+}
+
+impl<S: Select, O: GroupOrder> GroupBy<S, O> {
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table()
+    ///     .select()
+    ///     .group_by(|u| u.id)
+    ///     .having(|u| u.id.gt(5));
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql(),
+    ///     "SELECT * FROM users GROUP BY users.id HAVING users.id > 5;"
+    /// );
+    /// ```
+    pub fn having<F, P>(self, f: F) -> Having<Self, P>
+    where
+        F: FnOnce(<S::Selectable as Selectable>::Fields) -> P,
+        P: Predicate,
+    {
+        Having::new(self, f(Default::default()))
+    }
+}
+
+impl<G: Clause, P: Predicate> Clause for Having<G, P> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} HAVING {}",
+            self.source.render(dialect),
+            self.predicate.to_sql_dialect(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} HAVING {}",
+            self.source.render_params(dialect, values),
+            self.predicate.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
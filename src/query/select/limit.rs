@@ -0,0 +1,37 @@
+//! `LIMIT`.
+
+use super::Select;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::Dialect;
+
+/// `<source> LIMIT <limit>`
+pub struct Limit<S> {
+    source: S,
+    limit: usize,
+}
+
+impl<S> Limit<S> {
+    pub(crate) fn new(source: S, limit: usize) -> Self {
+        Self { source, limit }
+    }
+}
+
+impl<S: Select> Select for Limit<S> {
+    type Selectable = S::Selectable;
+    type Query = S::Query;
+}
+
+impl<S: Clause> Clause for Limit<S> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!("{} LIMIT {}", self.source.render(dialect), self.limit)
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} LIMIT {}",
+            self.source.render_params(dialect, values),
+            self.limit
+        )
+    }
+}
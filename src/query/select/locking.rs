@@ -0,0 +1,195 @@
+//! Row-locking clauses: `FOR UPDATE` / `FOR SHARE`, with optional
+//! `SKIP LOCKED` / `NOWAIT` modifiers.
+
+use super::Select;
+use crate::dialect::SupportsLocking;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::Dialect;
+
+/// Which row-locking clause a [`Locking`] renders.
+pub(crate) enum Mode {
+    ForUpdate,
+    ForShare,
+}
+
+impl Mode {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Mode::ForUpdate => "FOR UPDATE",
+            Mode::ForShare => "FOR SHARE",
+        }
+    }
+}
+
+/// What to do about rows already locked by another transaction.
+pub(crate) enum Wait {
+    Block,
+    SkipLocked,
+    No,
+}
+
+impl Wait {
+    fn keyword(&self) -> Option<&'static str> {
+        match self {
+            Wait::Block => None,
+            Wait::SkipLocked => Some("SKIP LOCKED"),
+            Wait::No => Some("NOWAIT"),
+        }
+    }
+}
+
+/// `<source> FOR UPDATE|FOR SHARE [SKIP LOCKED|NOWAIT]`
+///
+/// Built from [`Lockable::for_update`]/[`Lockable::for_share`]; placed
+/// after `ORDER BY`/`LIMIT`/`OFFSET` in the rendered statement, same as
+/// real SQL puts it last.
+///
+/// Unlike most combinators in this crate, `Locking` doesn't
+/// implement `Clause`/`ToSql` — those render for every [`Dialect`], and
+/// Sqlite has no row-locking clause at all. Instead it exposes its own
+/// [`to_sql_dialect`](Locking::to_sql_dialect)/
+/// [`to_sql_params_dialect`](Locking::to_sql_params_dialect), bounded by
+/// [`SupportsLocking`], so a dialect without the clause is rejected at
+/// compile time instead of silently rendering invalid SQL.
+pub struct Locking<S> {
+    source: S,
+    mode: Mode,
+    wait: Wait,
+}
+
+impl<S> Locking<S> {
+    pub(crate) fn new(source: S, mode: Mode) -> Self {
+        Self {
+            source,
+            mode,
+            wait: Wait::Block,
+        }
+    }
+
+    /// Adds `SKIP LOCKED`: rows already locked by another transaction are
+    /// left out of the result instead of waiting for them.
+    pub fn skip_locked(mut self) -> Self {
+        self.wait = Wait::SkipLocked;
+        self
+    }
+
+    /// Adds `NOWAIT`: errors immediately instead of waiting if a row is
+    /// already locked by another transaction.
+    pub fn no_wait(mut self) -> Self {
+        self.wait = Wait::No;
+        self
+    }
+}
+
+impl<S: Select> Select for Locking<S> {
+    type Selectable = S::Selectable;
+    type Query = S::Query;
+}
+
+// `Clause` is deliberately `pub(crate)` (sealed — see `query::clause`), so
+// this inherent impl intentionally bounds public methods on a private
+// trait; that's fine, callers just can't implement `Clause` themselves.
+#[allow(private_bounds)]
+impl<S: Clause> Locking<S> {
+    /// # Examples
+    /// ```
+    /// use typed_sql::dialect::Postgres;
+    /// use typed_sql::query::select::Lockable;
+    /// use typed_sql::{Field, Query, Table};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table()
+    ///     .select()
+    ///     .order_by(|u| u.id)
+    ///     .limit(10)
+    ///     .for_update()
+    ///     .skip_locked();
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql_dialect(&Postgres),
+    ///     "SELECT * FROM \"users\" ORDER BY \"users\".\"id\" LIMIT 10 FOR UPDATE SKIP LOCKED;"
+    /// );
+    /// ```
+    pub fn to_sql_dialect<D: SupportsLocking>(&self, dialect: &D) -> String {
+        format!("{};", self.render(dialect))
+    }
+
+    /// The `to_sql_dialect` counterpart that binds literals instead of
+    /// inlining them; see [`ToSql::to_sql_params_dialect`](crate::ToSql::to_sql_params_dialect).
+    pub fn to_sql_params_dialect<D: SupportsLocking>(
+        &self,
+        dialect: &D,
+        values: &mut Vec<Literal>,
+    ) -> String {
+        format!("{};", self.render_params(dialect, values))
+    }
+
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        match self.wait.keyword() {
+            Some(wait) => format!(
+                "{} {} {}",
+                self.source.render(dialect),
+                self.mode.keyword(),
+                wait
+            ),
+            None => format!("{} {}", self.source.render(dialect), self.mode.keyword()),
+        }
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        match self.wait.keyword() {
+            Some(wait) => format!(
+                "{} {} {}",
+                self.source.render_params(dialect, values),
+                self.mode.keyword(),
+                wait
+            ),
+            None => format!(
+                "{} {}",
+                self.source.render_params(dialect, values),
+                self.mode.keyword()
+            ),
+        }
+    }
+}
+
+/// Adds [`for_update`](Lockable::for_update)/[`for_share`](Lockable::for_share)
+/// to any `SELECT` chain.
+pub trait Lockable: Select + Sized {
+    /// `SELECT ... FOR UPDATE` — locks the selected rows against
+    /// concurrent updates or deletes until the transaction ends.
+    fn for_update(self) -> Locking<Self> {
+        Locking::new(self, Mode::ForUpdate)
+    }
+
+    /// `SELECT ... FOR SHARE` — locks the selected rows against
+    /// concurrent updates or deletes, but allows other transactions to
+    /// take the same shared lock.
+    fn for_share(self) -> Locking<Self> {
+        Locking::new(self, Mode::ForShare)
+    }
+}
+
+impl<S: Select> Lockable for S {}
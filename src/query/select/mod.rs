@@ -0,0 +1,249 @@
+//! Everything that can follow `SELECT`: the column list, `GROUP BY`,
+//! `ORDER BY`, `LIMIT`, joins, and the `HAVING` clause built on top of
+//! `GROUP BY`.
+
+pub mod queryable;
+
+mod combine;
+mod distinct;
+mod group_by;
+mod having;
+mod limit;
+mod locking;
+mod offset;
+mod order_by;
+
+pub use combine::Combine;
+pub use distinct::{Distinct, DistinctOn};
+pub use group_by::{GroupBy, GroupOrder};
+pub use having::Having;
+pub use limit::Limit;
+pub use locking::{Lockable, Locking};
+pub use offset::Offset;
+pub use order_by::{Order, OrderBy};
+
+use combine::Operator;
+
+use std::marker::PhantomData;
+
+use super::clause::Clause;
+use super::filter::Filterable;
+use crate::field::Literal;
+use crate::table::{Table, TableHandle};
+use crate::Dialect;
+use queryable::Queryable;
+
+/// A table or statement whose rows can be projected with `SELECT`.
+///
+/// Implemented at the root by [`TableHandle`]; every combinator below
+/// forwards it to whatever it wraps so the column list stays reachable
+/// down the whole chain.
+pub trait Selectable {
+    /// One [`Field`](crate::Field) per column, handed to `query`/`count`/
+    /// `group_by`/`order_by` closures.
+    type Fields: Default;
+
+    fn table_name() -> &'static str;
+}
+
+impl<T: Table> Selectable for TableHandle<T> {
+    type Fields = T::Fields;
+
+    fn table_name() -> &'static str {
+        T::NAME
+    }
+}
+
+impl<T: Table> Filterable for TableHandle<T> {
+    type Fields = T::Fields;
+}
+
+/// A query chain rooted at a `SELECT`; tracks which [`Selectable`] the
+/// column list is drawn from so later combinators (`group_by`,
+/// `order_by`, ...) can still access its `Fields`.
+pub trait Select {
+    type Selectable: Selectable;
+
+    /// What sits between `SELECT` and `FROM` — `*`, a field, or an
+    /// aggregate. [`union`](Combinable::union) and friends require both
+    /// sides to share this so the combined statement's columns line up.
+    type Query: Queryable;
+}
+
+/// `SELECT <query> FROM <table>`
+pub struct SelectStatement<S, Q> {
+    _source: PhantomData<S>,
+    query: Q,
+}
+
+impl<S, Q> SelectStatement<S, Q> {
+    pub(crate) fn new(_source: S, query: Q) -> Self {
+        Self {
+            _source: PhantomData,
+            query,
+        }
+    }
+}
+
+impl<S: Selectable, Q: Queryable> Select for SelectStatement<S, Q> {
+    type Selectable = S;
+    type Query = Q;
+}
+
+impl<S: Selectable, Q> Filterable for SelectStatement<S, Q> {
+    type Fields = S::Fields;
+}
+
+impl<S: Selectable, Q: Queryable> Clause for SelectStatement<S, Q> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "SELECT {} FROM {}",
+            self.query.to_sql_dialect(dialect),
+            D::quote(S::table_name())
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "SELECT {} FROM {}",
+            self.query.to_sql_params_dialect(dialect, values),
+            D::quote(S::table_name())
+        )
+    }
+}
+
+/// Marks a [`Select`] that has joined in another table.
+pub trait Join: Select + Sized {
+    fn join<R: Selectable>(self, _other: R) -> Joined<Self, R> {
+        Joined {
+            left: self,
+            _right: PhantomData,
+        }
+    }
+}
+
+impl<S: Select> Join for S {}
+
+/// `<left> JOIN <right>`
+pub struct Joined<L, R> {
+    left: L,
+    _right: PhantomData<R>,
+}
+
+impl<L: Select, R: Selectable> Select for Joined<L, R> {
+    type Selectable = L::Selectable;
+    type Query = L::Query;
+}
+
+impl<L: Clause, R: Selectable> Clause for Joined<L, R> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} JOIN {}",
+            self.left.render(dialect),
+            D::quote(R::table_name())
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} JOIN {}",
+            self.left.render_params(dialect, values),
+            D::quote(R::table_name())
+        )
+    }
+}
+
+/// Set-operation combinators (`UNION`/`UNION ALL`/`INTERSECT`/`EXCEPT`) on
+/// top of a `SELECT`.
+pub trait Combinable: Select + Sized {
+    /// `self UNION other` — rows from both sides, duplicates removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::query::select::Combinable;
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// // ... and for:
+    /// // #[derive(Table)]
+    /// // struct Admin {
+    /// //     id: i64,
+    /// // }
+    /// struct Admin;
+    ///
+    /// struct AdminFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for AdminFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("admins", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for Admin {
+    ///     const NAME: &'static str = "admins";
+    ///     type Fields = AdminFields;
+    /// }
+    ///
+    /// let stmt = User::table().select().union(Admin::table().select());
+    ///
+    /// assert_eq!(
+    ///     stmt.to_sql(),
+    ///     "SELECT * FROM users UNION SELECT * FROM admins;"
+    /// );
+    /// ```
+    fn union<R>(self, other: R) -> Combine<Self, R>
+    where
+        R: Select<Query = Self::Query>,
+    {
+        Combine::new(self, other, Operator::Union)
+    }
+
+    /// `self UNION ALL other` — rows from both sides, duplicates kept.
+    fn union_all<R>(self, other: R) -> Combine<Self, R>
+    where
+        R: Select<Query = Self::Query>,
+    {
+        Combine::new(self, other, Operator::UnionAll)
+    }
+
+    /// `self INTERSECT other`.
+    fn intersect<R>(self, other: R) -> Combine<Self, R>
+    where
+        R: Select<Query = Self::Query>,
+    {
+        Combine::new(self, other, Operator::Intersect)
+    }
+
+    /// `self EXCEPT other`.
+    fn except<R>(self, other: R) -> Combine<Self, R>
+    where
+        R: Select<Query = Self::Query>,
+    {
+        Combine::new(self, other, Operator::Except)
+    }
+}
+
+impl<S: Select> Combinable for S {}
@@ -0,0 +1,42 @@
+//! `OFFSET`.
+
+use super::Select;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::Dialect;
+
+/// `<source> OFFSET <offset>`
+///
+/// Only meaningful paired with a [`Limit`](super::Limit) (see
+/// [`Query::paginate`](crate::Query::paginate)), but SQL allows a bare
+/// `OFFSET` too, so it's gated the same way `limit` is: available on
+/// anything [`Select`].
+pub struct Offset<S> {
+    source: S,
+    offset: usize,
+}
+
+impl<S> Offset<S> {
+    pub(crate) fn new(source: S, offset: usize) -> Self {
+        Self { source, offset }
+    }
+}
+
+impl<S: Select> Select for Offset<S> {
+    type Selectable = S::Selectable;
+    type Query = S::Query;
+}
+
+impl<S: Clause> Clause for Offset<S> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!("{} OFFSET {}", self.source.render(dialect), self.offset)
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} OFFSET {}",
+            self.source.render_params(dialect, values),
+            self.offset
+        )
+    }
+}
@@ -0,0 +1,101 @@
+//! `ORDER BY`.
+
+use super::Select;
+use crate::field::Literal;
+use crate::query::clause::Clause;
+use crate::{Dialect, ToSql};
+
+/// One or more columns to order by, each optionally wrapped in
+/// [`ascending`](crate::Field::ascending)/[`descending`](crate::Field::descending).
+///
+/// Implemented directly by [`Field`](crate::Field) (bare column, no
+/// explicit direction), by [`Ascending`]/[`Descending`], and by tuples
+/// (built up via [`then`](Order::then)) for several columns.
+pub trait Order: ToSql {
+    /// Adds another column to order by.
+    fn then<O: Order>(self, other: O) -> (Self, O)
+    where
+        Self: Sized,
+    {
+        (self, other)
+    }
+}
+
+impl<A: Order, B: Order> Order for (A, B) {}
+
+/// `<field> ASC`
+pub struct Ascending<F>(F);
+
+impl<F: ToSql> ToSql for Ascending<F> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("{} ASC", self.0.to_sql_dialect(dialect))
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!("{} ASC", self.0.to_sql_params_dialect(dialect, values))
+    }
+}
+
+impl<F: ToSql> Order for Ascending<F> {}
+
+/// `<field> DESC`
+pub struct Descending<F>(F);
+
+impl<F: ToSql> ToSql for Descending<F> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("{} DESC", self.0.to_sql_dialect(dialect))
+    }
+
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!("{} DESC", self.0.to_sql_params_dialect(dialect, values))
+    }
+}
+
+impl<F: ToSql> Order for Descending<F> {}
+
+impl<T> crate::Field<T> {
+    /// `field ASC`
+    pub fn ascending(self) -> Ascending<Self> {
+        Ascending(self)
+    }
+
+    /// `field DESC`
+    pub fn descending(self) -> Descending<Self> {
+        Descending(self)
+    }
+}
+
+/// `<source> ORDER BY <order>`
+pub struct OrderBy<S, O> {
+    source: S,
+    order: O,
+}
+
+impl<S, O> OrderBy<S, O> {
+    pub(crate) fn new(source: S, order: O) -> Self {
+        Self { source, order }
+    }
+}
+
+impl<S: Select, O> Select for OrderBy<S, O> {
+    type Selectable = S::Selectable;
+    type Query = S::Query;
+}
+
+impl<S: Clause, O: Order> Clause for OrderBy<S, O> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "{} ORDER BY {}",
+            self.source.render(dialect),
+            self.order.to_sql_dialect(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "{} ORDER BY {}",
+            self.source.render_params(dialect, values),
+            self.order.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
@@ -0,0 +1,129 @@
+//! What can sit between `SELECT` and `FROM`: `*`, a field, or an
+//! aggregate wrapping one.
+
+use crate::{Dialect, ToSql};
+
+/// Anything renderable as a `SELECT` column list: `*`, a single
+/// [`Field`](crate::Field), or an aggregate like [`Count`].
+pub trait Queryable: ToSql {}
+
+/// `SELECT *`
+pub struct WildCard;
+
+impl ToSql for WildCard {
+    fn to_sql_dialect<D: Dialect>(&self, _dialect: &D) -> String {
+        "*".to_string()
+    }
+}
+
+impl Queryable for WildCard {}
+
+impl<T> Queryable for crate::Field<T> {}
+
+// `count(|_| {})` picks out no field at all, so its closure returns `()`;
+// letting `()` render the same way `WildCard` does is what makes
+// `Count<()>` come out as `COUNT(*)` below, with no separate impl needed.
+impl ToSql for () {
+    fn to_sql_dialect<D: Dialect>(&self, _dialect: &D) -> String {
+        "*".to_string()
+    }
+}
+
+/// `COUNT(<field>)`, or `COUNT(*)` when built from `count(|_| {})`.
+pub struct Count<T> {
+    field: T,
+}
+
+impl<T> Count<T> {
+    pub(crate) fn new(field: T) -> Self {
+        Self { field }
+    }
+}
+
+impl<T: ToSql> ToSql for Count<T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("COUNT({})", self.field.to_sql_dialect(dialect))
+    }
+}
+
+impl<T: ToSql> Queryable for Count<T> {}
+
+/// `SUM(<field>)`.
+///
+/// Unlike `COUNT`, which returns `0` over an empty set, `SUM` (and
+/// `AVG`/`MIN`/`MAX` below) returns `NULL` — so reading this column back
+/// needs an `Option<...>` target even when `field` itself is non-nullable.
+pub struct Sum<T> {
+    field: T,
+}
+
+impl<T> Sum<T> {
+    pub(crate) fn new(field: T) -> Self {
+        Self { field }
+    }
+}
+
+impl<T: ToSql> ToSql for Sum<T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("SUM({})", self.field.to_sql_dialect(dialect))
+    }
+}
+
+impl<T: ToSql> Queryable for Sum<T> {}
+
+/// `AVG(<field>)`. `NULL` over an empty set, same as `SUM`.
+pub struct Avg<T> {
+    field: T,
+}
+
+impl<T> Avg<T> {
+    pub(crate) fn new(field: T) -> Self {
+        Self { field }
+    }
+}
+
+impl<T: ToSql> ToSql for Avg<T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("AVG({})", self.field.to_sql_dialect(dialect))
+    }
+}
+
+impl<T: ToSql> Queryable for Avg<T> {}
+
+/// `MIN(<field>)`. `NULL` over an empty set, same as `SUM`.
+pub struct Min<T> {
+    field: T,
+}
+
+impl<T> Min<T> {
+    pub(crate) fn new(field: T) -> Self {
+        Self { field }
+    }
+}
+
+impl<T: ToSql> ToSql for Min<T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("MIN({})", self.field.to_sql_dialect(dialect))
+    }
+}
+
+impl<T: ToSql> Queryable for Min<T> {}
+
+/// `MAX(<field>)`. `NULL` over an empty set, same as `SUM`.
+pub struct Max<T> {
+    field: T,
+}
+
+impl<T> Max<T> {
+    pub(crate) fn new(field: T) -> Self {
+        Self { field }
+    }
+}
+
+impl<T: ToSql> ToSql for Max<T> {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String {
+        format!("MAX({})", self.field.to_sql_dialect(dialect))
+    }
+}
+
+impl<T: ToSql> Queryable for Max<T> {}
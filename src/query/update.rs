@@ -0,0 +1,57 @@
+//! `UPDATE ... SET`.
+
+use std::marker::PhantomData;
+
+use super::clause::Clause;
+use super::filter::Filterable;
+use crate::field::Literal;
+use crate::table::Table;
+use crate::Dialect;
+
+/// One or more `column = value` assignments, built from `eq` calls
+/// combined with [`Query::and`](crate::Query::and).
+pub trait UpdateSet {
+    fn to_sql_dialect<D: Dialect>(&self, dialect: &D) -> String;
+
+    /// The `UpdateSet` counterpart of
+    /// [`ToSql::to_sql_params_dialect`](crate::ToSql::to_sql_params_dialect):
+    /// binds each assigned value instead of inlining it.
+    fn to_sql_params_dialect<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String;
+}
+
+/// `UPDATE <table> SET <set>`
+pub struct Update<T, S> {
+    set: S,
+    _table: PhantomData<T>,
+}
+
+impl<T, S> Update<T, S> {
+    pub(crate) fn new(set: S) -> Self {
+        Self {
+            set,
+            _table: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, S> Filterable for Update<T, S> {
+    type Fields = T::Fields;
+}
+
+impl<T: Table, S: UpdateSet> Clause for Update<T, S> {
+    fn render<D: Dialect>(&self, dialect: &D) -> String {
+        format!(
+            "UPDATE {} SET {}",
+            D::quote(T::NAME),
+            self.set.to_sql_dialect(dialect)
+        )
+    }
+
+    fn render_params<D: Dialect>(&self, dialect: &D, values: &mut Vec<Literal>) -> String {
+        format!(
+            "UPDATE {} SET {}",
+            D::quote(T::NAME),
+            self.set.to_sql_params_dialect(dialect, values)
+        )
+    }
+}
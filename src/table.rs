@@ -0,0 +1,88 @@
+//! The [`Table`] trait that `#[derive(Table)]` implements, and the handle
+//! it hands back from [`Table::table`] to start a query.
+
+use std::marker::PhantomData;
+
+/// A Rust struct that maps onto a SQL table.
+///
+/// Implemented by `#[derive(Table)]`; the derive also generates `Fields`,
+/// a struct of [`Field`](crate::Field)s (one per table column) that every
+/// `Query` combinator closure receives so column access is type-checked.
+pub trait Table: Sized {
+    /// The table name as it appears in `FROM`/`UPDATE`/`DELETE FROM`.
+    const NAME: &'static str;
+
+    /// One [`Field`](crate::Field) per column, handed to combinator
+    /// closures such as `filter(|t| ...)`.
+    type Fields: Default;
+
+    /// Starts a query against this table.
+    ///
+    /// # Examples
+    /// ```
+    /// use typed_sql::{Field, Query, Table, ToSql};
+    ///
+    /// // What `#[derive(Table)]` would generate for:
+    /// // #[derive(Table)]
+    /// // struct User {
+    /// //     id: i64,
+    /// // }
+    /// struct User;
+    ///
+    /// struct UserFields {
+    ///     id: Field<i64>,
+    /// }
+    ///
+    /// impl Default for UserFields {
+    ///     fn default() -> Self {
+    ///         Self { id: Field::new("users", "id") }
+    ///     }
+    /// }
+    ///
+    /// impl Table for User {
+    ///     const NAME: &'static str = "users";
+    ///     type Fields = UserFields;
+    /// }
+    ///
+    /// let stmt = User::table().select();
+    ///
+    /// assert_eq!(stmt.to_sql(), "SELECT * FROM users;");
+    /// ```
+    fn table() -> TableHandle<Self> {
+        TableHandle::new()
+    }
+}
+
+/// The entry point returned by [`Table::table`]; carries no data of its
+/// own; it only identifies which table the query chain starting here is
+/// rooted at.
+pub struct TableHandle<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<T> TableHandle<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+// `TableHandle<T>` carries no data, so cloning/copying it is free.
+impl<T> Clone for TableHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for TableHandle<T> {}
+
+/// Resolves the underlying [`Table`] of a query chain rooted at a table
+/// (as opposed to one rooted at a `SELECT`, which instead goes through
+/// `Selectable`).
+pub trait TableQueryable {
+    type Table: Table;
+}
+
+impl<T: Table> TableQueryable for TableHandle<T> {
+    type Table = T;
+}